@@ -4,10 +4,19 @@
 //!
 //! It supports Rusty Object Notation (RON) files for translation data. Refer to the [RON documentation](https://docs.rs/ron) for more information.
 //!
-//! It exports a single macro `t!` that can be used to translate strings at runtime.
+//! It exports a `t!` macro that can be used to translate strings at runtime, and a `tn!` macro
+//! for messages that vary by a count argument (CLDR plural categories).
 //!
 //! For formatting, it uses the same syntax as the `format!` macro. Refer to the [formatx documentation](https://docs.rs/formatx) for more information.
 //!
+//! Beyond `from_ron!`/`with_ron!`/`locale!`/`t!`, the crate also exports:
+//! * `fallback!` - a locale chain to try when the active locale (and its BCP-47 parent subtags) has no translation.
+//! * `tn!` - like `t!`, but selects a CLDR plural category for a count argument.
+//! * `pseudo!` - switches to a pseudo-localization locale for spotting hard-coded strings and testing layout expansion.
+//! * `load_file!`/`load_dir!` - load RON translation files (or a glob of them) from disk at runtime.
+//! * `set_locale_from!` - negotiates the active locale from an ordered list of preferences, e.g. an `Accept-Language` header.
+//! * `take_missing!` - drains the set of `(locale, key)` pairs that fell back to the raw template, when opted into via `Dictionary::log_missing`.
+//!
 //! ## Usage
 //!
 //! ```rust
@@ -60,6 +69,12 @@
 use std::sync::Mutex;
 use once_cell::sync::OnceCell;
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::borrow::Cow;
+
+/// Reserved locale that triggers pseudo-localization instead of a stored translation lookup.
+/// Activate it with [`pseudo!`] (equivalent to `locale!("qps")`).
+pub const PSEUDO_LOCALE: &str = "qps";
 
 /// Dictionary holds current locale and a map of translations for each locale.
 ///
@@ -73,7 +88,7 @@ use std::collections::HashMap;
 ///     "fr": "Bonjour {name}!",
 ///   },
 /// }"#).unwrap();
-/// dict.locale = Some("fr");
+/// dict.locale = Some("fr".into());
 /// assert_eq!(
 ///     dict.get("Hello {name}!").unwrap(),
 ///     "Bonjour {name}!"
@@ -81,10 +96,124 @@ use std::collections::HashMap;
 /// ```
 #[derive(Default, Debug)]
 pub struct Dictionary {
-    /// Locale is a string that holds the current language.
-    pub locale: Option<&'static str>,
+    /// Locale is the active language. `Cow` so it can hold either a `&'static str` literal (e.g.
+    /// from [`locale!`]) or a locale discovered at runtime by [`negotiate`](Dictionary::negotiate)
+    /// over packs loaded via [`load_file`](Dictionary::load_file)/[`load_dir`](Dictionary::load_dir).
+    pub locale: Option<Cow<'static, str>>,
     /// The resource is a HashMap of translations, where the key is the message and the value is a HashMap of translations for each locale.
-    pub resource: HashMap<&'static str, HashMap<&'static str, &'static str>>,
+    ///
+    /// Keys and locale codes are `Cow<'static, str>`: [`with_ron`](Dictionary::with_ron) stores
+    /// its `&'static str` locale argument as `Cow::Borrowed`. Keys and translations deserialized
+    /// by [`from_ron`](Dictionary::from_ron)/[`with_ron`](Dictionary::with_ron), and everything
+    /// read from disk by [`load_file`](Dictionary::load_file)/[`load_dir`](Dictionary::load_dir),
+    /// are stored as owned strings, so reloading a changed file doesn't leak the previous contents.
+    pub resource: HashMap<Cow<'static, str>, HashMap<Cow<'static, str>, Translation>>,
+    /// Locales to try, in order, when the active locale (and its BCP-47 parent subtags) has no translation.
+    pub fallback: Vec<Cow<'static, str>>,
+    /// When `true`, every fallback to the raw template (no translation for the active locale) is
+    /// recorded in [`missing`](Dictionary::missing). Off by default; when off, misses are not
+    /// recorded and nothing is allocated for them.
+    pub log_missing: bool,
+    missing: HashSet<(Cow<'static, str>, &'static str)>,
+}
+
+/// A single locale's entry for a key: either a flat string, or a map of CLDR plural categories
+/// (`zero`, `one`, `two`, `few`, `many`, `other`) used to vary the message by a count argument.
+///
+/// RON accepts either shape for a locale's value:
+/// ```ron
+/// "en": "{n} files",
+/// "en": { "one": "{n} file", "other": "{n} files" },
+/// ```
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Translation {
+    Plain(Cow<'static, str>),
+    Categories(HashMap<Cow<'static, str>, Cow<'static, str>>),
+}
+
+impl Translation {
+    /// Resolves to the string for `category`, falling back to the mandatory `"other"` category.
+    /// A [`Translation::Plain`] resolves to itself regardless of `category`.
+    fn resolve(&self, category: &str) -> Option<Cow<'static, str>> {
+        match self {
+            Translation::Plain(translation) => Some(translation.clone()),
+            Translation::Categories(categories) => categories.get(category)
+                .or_else(|| categories.get("other"))
+                .cloned(),
+        }
+    }
+}
+
+/// Mirrors [`Translation`], but with plain owned `String`s: [`Dictionary::load_file`] deserializes
+/// into this from file contents that are not `'static` (they live only for the duration of the
+/// call), then converts into [`Translation`]'s `Cow::Owned` variants so the dictionary keeps its
+/// own copy without borrowing from (or leaking) the loaded buffer.
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+enum RawTranslation {
+    Plain(String),
+    Categories(HashMap<String, String>),
+}
+
+impl From<RawTranslation> for Translation {
+    fn from(raw: RawTranslation) -> Self {
+        match raw {
+            RawTranslation::Plain(s) => Translation::Plain(Cow::Owned(s)),
+            RawTranslation::Categories(categories) => Translation::Categories(
+                categories.into_iter().map(|(k, v)| (Cow::Owned(k), Cow::Owned(v))).collect(),
+            ),
+        }
+    }
+}
+
+/// Error returned by [`Dictionary::load_file`] / [`Dictionary::load_dir`].
+#[derive(Debug)]
+pub enum LoadError {
+    Io(std::io::Error),
+    Ron(ron::Error),
+    Pattern(glob::PatternError),
+    Glob(glob::GlobError),
+    /// The locale could not be inferred from the filename (expected e.g. `"name.<locale>.ron"`).
+    UnknownLocale(std::path::PathBuf),
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Io(err) => write!(f, "{err}"),
+            LoadError::Ron(err) => write!(f, "{err}"),
+            LoadError::Pattern(err) => write!(f, "{err}"),
+            LoadError::Glob(err) => write!(f, "{err}"),
+            LoadError::UnknownLocale(path) => write!(f, "could not infer locale from filename: {}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<std::io::Error> for LoadError {
+    fn from(err: std::io::Error) -> Self {
+        LoadError::Io(err)
+    }
+}
+
+impl From<ron::Error> for LoadError {
+    fn from(err: ron::Error) -> Self {
+        LoadError::Ron(err)
+    }
+}
+
+impl From<glob::PatternError> for LoadError {
+    fn from(err: glob::PatternError) -> Self {
+        LoadError::Pattern(err)
+    }
+}
+
+impl From<glob::GlobError> for LoadError {
+    fn from(err: glob::GlobError) -> Self {
+        LoadError::Glob(err)
+    }
 }
 
 impl Dictionary {
@@ -99,23 +228,262 @@ impl Dictionary {
         Ok(Dictionary {
             locale: None,
             resource: dict,
+            fallback: Vec::new(),
+            log_missing: false,
+            missing: HashSet::new(),
         })
     }
 
     /// Adds translations from RON string to the dictionary.
     pub fn with_ron(&mut self, locale: &'static str, ron: &'static str) -> Result<&mut Self, ron::Error> {
-        let dict: HashMap<&'static str, &'static str> = ron::from_str(ron)?;
-        for (key, translation) in dict.iter() {
-            self.resource.entry(key).or_default().insert(locale, *translation);
+        let dict: HashMap<Cow<'static, str>, Translation> = ron::from_str(ron)?;
+        for (key, translation) in dict.into_iter() {
+            self.resource.entry(key).or_default().insert(Cow::Borrowed(locale), translation);
+        }
+        Ok(self)
+    }
+
+    /// Sets the fallback locale chain, tried in order (after BCP-47 subtag stripping of the
+    /// active locale) when a key has no translation for the active locale.
+    pub fn with_fallback<I, S>(&mut self, fallback: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.fallback = fallback.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// All locales that have at least one translation anywhere in the dictionary.
+    pub fn available_locales(&self) -> HashSet<Cow<'static, str>> {
+        self.resource.values().flat_map(|translations| translations.keys().cloned()).collect()
+    }
+
+    /// Picks the best available locale for an ordered list of preferred languages (e.g. parsed
+    /// from an HTTP `Accept-Language` header or OS settings).
+    ///
+    /// For each requested tag in priority order, returns the first of: an exact match, a match on
+    /// the requested tag's primary subtag (`en-GB` matches available `en`), or the first available
+    /// locale sharing the requested primary language (`en-GB` matches available `en-US`). Ties in
+    /// that last tier are broken by sorting the available locales, so the result is deterministic
+    /// rather than depending on `HashSet` iteration order.
+    pub fn negotiate(&self, requested: &[&str]) -> Option<Cow<'static, str>> {
+        let mut available: Vec<Cow<'static, str>> = self.available_locales().into_iter().collect();
+        available.sort_unstable();
+        for &tag in requested {
+            if let Some(exact) = available.iter().find(|locale| locale.as_ref() == tag) {
+                return Some(exact.clone());
+            }
+            let primary = tag.split('-').next().unwrap_or(tag);
+            if let Some(matched) = available.iter().find(|locale| locale.as_ref() == primary) {
+                return Some(matched.clone());
+            }
+            if let Some(matched) = available.iter().find(|locale| locale.split('-').next().unwrap_or(locale.as_ref()) == primary) {
+                return Some(matched.clone());
+            }
+        }
+        None
+    }
+
+    /// Negotiates the best available locale for `requested` and sets it as active, returning it.
+    pub fn set_locale_from(&mut self, requested: &[&str]) -> Option<Cow<'static, str>> {
+        let locale = self.negotiate(requested);
+        self.locale = locale.clone();
+        locale
+    }
+
+    /// Reads a RON translation file from disk and merges it into the dictionary, inferring its
+    /// locale from the filename (`"demo.ru.ron"` -> `"ru"`).
+    ///
+    /// The file contents here are never `'static` — they're read fresh on every call — so they,
+    /// and the inferred locale, are stored as owned [`Cow::Owned`] strings in
+    /// [`resource`](Dictionary::resource). Reloading a
+    /// directory after a translator edits a file (e.g. in a hot-reload loop) simply replaces the
+    /// previous owned strings; nothing is leaked.
+    pub fn load_file<P: AsRef<std::path::Path>>(&mut self, path: P) -> Result<&mut Self, LoadError> {
+        let path = path.as_ref();
+        let locale = Self::locale_from_filename(path)
+            .ok_or_else(|| LoadError::UnknownLocale(path.to_path_buf()))?;
+        let ron = std::fs::read_to_string(path)?;
+        self.with_ron_owned(locale, &ron)?;
+        Ok(self)
+    }
+
+    /// Loads every file matching a glob `pattern` (e.g. `"locales/*.ron"`) via [`load_file`](Dictionary::load_file).
+    pub fn load_dir(&mut self, pattern: &str) -> Result<&mut Self, LoadError> {
+        for entry in glob::glob(pattern)? {
+            self.load_file(entry?)?;
         }
         Ok(self)
     }
 
+    /// Merges RON content that isn't `'static` (read from disk at runtime) into the dictionary
+    /// under `locale`, via [`RawTranslation`]'s owned deserialization.
+    fn with_ron_owned(&mut self, locale: String, ron: &str) -> Result<&mut Self, ron::Error> {
+        let dict: HashMap<String, RawTranslation> = ron::from_str(ron)?;
+        let locale: Cow<'static, str> = Cow::Owned(locale);
+        for (key, translation) in dict.into_iter() {
+            self.resource.entry(Cow::Owned(key)).or_default().insert(locale.clone(), translation.into());
+        }
+        Ok(self)
+    }
+
+    /// Infers a locale from a translation filename: the last dot-separated segment of the file
+    /// stem, e.g. `"demo.ru.ron"` -> `Some("ru")`, `"demo.ron"` -> `None`.
+    fn locale_from_filename(path: &std::path::Path) -> Option<String> {
+        let stem = path.file_stem()?.to_str()?;
+        let (_, locale) = stem.rsplit_once('.')?;
+        Some(locale.to_string())
+    }
+
+    /// Expands a locale such as `"de-AT-1996"` into itself and its BCP-47 parent subtags,
+    /// most specific first: `["de-AT-1996", "de-AT", "de"]`.
+    fn subtag_chain(locale: &str) -> Vec<String> {
+        let mut chain = Vec::new();
+        let mut candidate = locale.to_string();
+        loop {
+            chain.push(candidate.clone());
+            match candidate.rfind('-') {
+                Some(idx) => candidate.truncate(idx),
+                None => break,
+            }
+        }
+        chain
+    }
+
+    /// Finds the `Translation` entry for `key`, trying the active locale, then its BCP-47 parent
+    /// subtags right-to-left, then each locale in [`fallback`](Dictionary::fallback) in order.
+    /// Returns the matched locale alongside the entry, since that locale's plural rules (not
+    /// necessarily the originally active locale's) decide the category in [`get_plural`](Dictionary::get_plural).
+    fn find(&self, key: &'static str) -> Option<(Cow<'static, str>, &Translation)> {
+        let translations = self.resource.get(key)?;
+        let locale = self.locale.as_deref()?;
+        for candidate in Self::subtag_chain(locale) {
+            if let Some((matched, translation)) = translations.get_key_value(candidate.as_str()) {
+                return Some((matched.clone(), translation));
+            }
+        }
+        for fallback in &self.fallback {
+            if let Some(translation) = translations.get(fallback.as_ref()) {
+                return Some((fallback.clone(), translation));
+            }
+        }
+        None
+    }
+
     /// Lookup a translation for the given key and locale.
-    pub fn get(&self, key: &'static str) -> Option<&'static str> {
-        self.resource.get(key).and_then(move |translations| {
-            translations.get(self.locale.unwrap())
-        }).copied()
+    ///
+    /// Tries the active locale, then its BCP-47 parent subtags right-to-left, then each locale
+    /// in [`fallback`](Dictionary::fallback) in order, returning the first translation found.
+    /// If the matched entry is plural [`Translation::Categories`], the `"other"` category is used.
+    ///
+    /// When the active locale is [`PSEUDO_LOCALE`], no lookup happens: `key` itself is returned,
+    /// transformed for pseudo-localization (see [`pseudo!`]).
+    ///
+    /// When [`log_missing`](Dictionary::log_missing) is enabled, a miss is recorded in
+    /// [`missing`](Dictionary::missing) as `(locale, key)`.
+    pub fn get(&mut self, key: &'static str) -> Option<Cow<'static, str>> {
+        if self.locale.as_deref() == Some(PSEUDO_LOCALE) {
+            return Some(Cow::Owned(Self::pseudo_transform(key)));
+        }
+        let translation = self.find(key).and_then(|(_, translation)| translation.resolve("other"));
+        self.record_if_missing(key, translation.is_none());
+        translation
+    }
+
+    fn record_if_missing(&mut self, key: &'static str, missing: bool) {
+        if missing && self.log_missing {
+            if let Some(locale) = self.locale.clone() {
+                self.missing.insert((locale, key));
+            }
+        }
+    }
+
+    /// Returns the `(locale, key)` pairs that fell back to the raw template since the last
+    /// [`take_missing`](Dictionary::take_missing) (or since startup). Only populated when
+    /// [`log_missing`](Dictionary::log_missing) is enabled.
+    pub fn missing(&self) -> &HashSet<(Cow<'static, str>, &'static str)> {
+        &self.missing
+    }
+
+    /// Drains and returns the recorded missing-translation set.
+    pub fn take_missing(&mut self) -> HashSet<(Cow<'static, str>, &'static str)> {
+        std::mem::take(&mut self.missing)
+    }
+
+    /// Transforms `template` for pseudo-localization: maps ASCII vowels and `n`, `s`, `h` (either
+    /// case) to accented look-alikes,
+    /// leaves `{...}` `formatx` placeholders untouched, and pads the result (repeated vowels,
+    /// bracket wrapping) so truncation and untranslated strings are easy to spot in the UI.
+    fn pseudo_transform(template: &str) -> String {
+        const MAP: &[(char, char)] = &[
+            ('a', 'á'), ('e', 'é'), ('i', 'í'), ('o', 'ø'), ('u', 'ü'),
+            ('A', 'Á'), ('E', 'É'), ('I', 'Í'), ('O', 'Ø'), ('U', 'Ü'),
+            ('n', 'ñ'), ('N', 'Ñ'), ('s', 'š'), ('S', 'Š'), ('h', 'ħ'), ('H', 'Ħ'),
+        ];
+
+        let mut body = String::with_capacity(template.len() * 2);
+        let mut chars = template.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '{' {
+                body.push(c);
+                for next in chars.by_ref() {
+                    body.push(next);
+                    if next == '}' {
+                        break;
+                    }
+                }
+                continue;
+            }
+            match MAP.iter().find(|(from, _)| *from == c) {
+                Some((_, to)) => {
+                    body.push(*to);
+                    if "aeiouAEIOU".contains(c) {
+                        body.push(*to);
+                    }
+                }
+                None => body.push(c),
+            }
+        }
+        format!("[{body}]")
+    }
+
+    /// Like [`get`](Dictionary::get), but for a message that varies by `count`: selects the
+    /// CLDR plural category for `count` under the matched locale's plural rules.
+    ///
+    /// When the active locale is [`PSEUDO_LOCALE`], no lookup happens: `key` itself is returned,
+    /// transformed for pseudo-localization (see [`pseudo!`]), same as [`get`](Dictionary::get).
+    pub fn get_plural(&mut self, key: &'static str, count: i64) -> Option<Cow<'static, str>> {
+        if self.locale.as_deref() == Some(PSEUDO_LOCALE) {
+            return Some(Cow::Owned(Self::pseudo_transform(key)));
+        }
+        let translation = self.find(key).and_then(|(locale, translation)| {
+            translation.resolve(Self::plural_category(locale.as_ref(), count))
+        });
+        self.record_if_missing(key, translation.is_none());
+        translation
+    }
+
+    /// Selects the CLDR plural category (`zero`, `one`, `two`, `few`, `many`, `other`) for `count`
+    /// under the given locale's plural rules. Unknown locales default to English-style rules.
+    fn plural_category(locale: &str, count: i64) -> &'static str {
+        let primary = locale.split('-').next().unwrap_or(locale);
+        let n = count.unsigned_abs();
+        match primary {
+            "fr" => if n == 0 || n == 1 { "one" } else { "other" },
+            "ru" | "uk" | "be" | "sr" | "hr" | "bs" => {
+                let mod10 = n % 10;
+                let mod100 = n % 100;
+                if mod10 == 1 && mod100 != 11 {
+                    "one"
+                } else if (2..=4).contains(&mod10) && !(12..=14).contains(&mod100) {
+                    "few"
+                } else {
+                    "many"
+                }
+            }
+            _ => if n == 1 { "one" } else { "other" },
+        }
     }
 }
 
@@ -132,9 +500,13 @@ pub fn global_dictionary() -> &'static Mutex<Dictionary> {
 macro_rules! new {
     () => {{
         let mut dict = $crate::global_dictionary().lock().unwrap();
-        let locale = dict.locale;
+        let locale = dict.locale.clone();
+        let fallback = dict.fallback.clone();
+        let log_missing = dict.log_missing;
         *dict = $crate::Dictionary::new();
         dict.locale = locale;
+        dict.fallback = fallback;
+        dict.log_missing = log_missing;
     }}
 }
 
@@ -143,9 +515,13 @@ macro_rules! new {
 macro_rules! from_ron {
     ($ron:expr) => {{
         let mut dict = $crate::global_dictionary().lock().unwrap();
-        let locale = dict.locale;
+        let locale = dict.locale.clone();
+        let fallback = dict.fallback.clone();
+        let log_missing = dict.log_missing;
         *dict = $crate::Dictionary::from_ron($ron).unwrap();
         dict.locale = locale;
+        dict.fallback = fallback;
+        dict.log_missing = log_missing;
     }}
 }
 
@@ -165,7 +541,25 @@ macro_rules! locale {
     }};
 
     ($locale:expr) => {{
-        $crate::global_dictionary().lock().unwrap().locale = Some($locale);
+        $crate::global_dictionary().lock().unwrap().locale = Some($locale.into());
+    }};
+}
+
+/// Same as [Dictionary::with_fallback](struct.Dictionary.html#method.with_fallback) but uses global dictionary.
+#[macro_export]
+macro_rules! fallback {
+    ($($locale:expr),* $(,)?) => {{
+        $crate::global_dictionary().lock().unwrap().fallback = vec![$($locale.into()),*];
+    }};
+}
+
+/// Activates pseudo-localization on the global dictionary (equivalent to `locale!(i18nx::PSEUDO_LOCALE)`):
+/// [`t!`]/[`Dictionary::get`] return a transformed version of the source template instead of a
+/// stored translation, so hard-coded/untranslated strings and layout truncation are easy to spot.
+#[macro_export]
+macro_rules! pseudo {
+    () => {{
+        $crate::global_dictionary().lock().unwrap().locale = Some($crate::PSEUDO_LOCALE.into());
     }};
 }
 
@@ -173,10 +567,13 @@ macro_rules! locale {
 #[macro_export]
 macro_rules! t {
     ($template:literal) => {{
-        let dictionary = $crate::global_dictionary().lock().unwrap();
-        dictionary.locale.and_then(|locale| {
+        let mut dictionary = $crate::global_dictionary().lock().unwrap();
+        let active = dictionary.locale.is_some();
+        if active {
             dictionary.get($template)
-        }).unwrap_or($template)
+        } else {
+            None
+        }.unwrap_or(::std::borrow::Cow::Borrowed($template))
     }};
 
     ($template:expr, $($values:tt)*) => {{
@@ -184,3 +581,59 @@ macro_rules! t {
         formatx::formatx!(translated, $($values)*).unwrap()
     }};
 }
+
+/// Same as [Dictionary::get_plural](struct.Dictionary.html#method.get_plural) but uses the global
+/// dictionary: selects the CLDR plural category for `$count` and formats with the same
+/// `formatx` syntax as [`t!`].
+///
+/// ```ignore
+/// tn!("{n} files", n = count)
+/// ```
+#[macro_export]
+macro_rules! tn {
+    ($template:literal, $name:ident = $count:expr) => {{
+        let translated = {
+            let mut dictionary = $crate::global_dictionary().lock().unwrap();
+            let active = dictionary.locale.is_some();
+            if active {
+                dictionary.get_plural($template, $count as i64)
+            } else {
+                None
+            }.unwrap_or(::std::borrow::Cow::Borrowed($template))
+        };
+        formatx::formatx!(translated, $name = $count).unwrap()
+    }};
+}
+
+/// Same as [Dictionary::take_missing](struct.Dictionary.html#method.take_missing) but uses the
+/// global dictionary.
+#[macro_export]
+macro_rules! take_missing {
+    () => {{
+        $crate::global_dictionary().lock().unwrap().take_missing()
+    }};
+}
+
+/// Same as [Dictionary::load_file](struct.Dictionary.html#method.load_file) but uses global dictionary.
+#[macro_export]
+macro_rules! load_file {
+    ($path:expr) => {{
+        $crate::global_dictionary().lock().unwrap().load_file($path).unwrap();
+    }}
+}
+
+/// Same as [Dictionary::load_dir](struct.Dictionary.html#method.load_dir) but uses global dictionary.
+#[macro_export]
+macro_rules! load_dir {
+    ($pattern:expr) => {{
+        $crate::global_dictionary().lock().unwrap().load_dir($pattern).unwrap();
+    }}
+}
+
+/// Same as [Dictionary::set_locale_from](struct.Dictionary.html#method.set_locale_from) but uses global dictionary.
+#[macro_export]
+macro_rules! set_locale_from {
+    ($requested:expr) => {{
+        $crate::global_dictionary().lock().unwrap().set_locale_from($requested)
+    }};
+}
@@ -22,12 +22,20 @@ const EXAMPLE_CN: &'static str = r#"{
 }
 "#;
 
+const EXAMPLE_PLURAL: &'static str = r#"{
+  "{n} files": {
+    "en": { "one": "{n} file", "other": "{n} files" },
+    "ru": { "one": "{n} файл", "few": "{n} файла", "many": "{n} файлов", "other": "{n} файлов" },
+  },
+}
+"#;
+
 
 #[cfg(test)]
 mod test {
     use i18nx;
-    use i18nx::{t, Dictionary};
-    use crate::{EXAMPLE, EXAMPLE_RU, EXAMPLE_CN};
+    use i18nx::{t, Dictionary, Translation};
+    use crate::{EXAMPLE, EXAMPLE_RU, EXAMPLE_CN, EXAMPLE_PLURAL};
 
     #[test]
     fn global() {
@@ -92,7 +100,156 @@ mod test {
         dict.with_ron("ru", EXAMPLE_RU).unwrap()
             .with_ron("cn", EXAMPLE_CN).unwrap();
 
-        dict.locale = Some("fr");
+        dict.locale = Some("fr".into());
         assert_eq!(dict.get("Hello").unwrap(), "Bonjour");
     }
+
+    #[test]
+    fn fallback_chain() {
+        let mut dict = Dictionary::from_ron(EXAMPLE).unwrap();
+
+        // Regional subtag falls back to its parent language.
+        dict.locale = Some("de-AT-1996".into());
+        assert_eq!(dict.get("Hello").unwrap(), "Hallo");
+
+        // Unrelated locale falls back to the configured default chain.
+        dict.with_fallback(vec!["fr"]);
+        dict.locale = Some("cn".into());
+        assert_eq!(dict.get("Hello").unwrap(), "Bonjour");
+
+        // Still returns None (and the `t!` template wins) when nothing matches.
+        dict.fallback = vec!["jp".into()];
+        assert_eq!(dict.get("Hello"), None);
+    }
+
+    #[test]
+    fn negotiate_language() {
+        let mut dict = Dictionary::from_ron(EXAMPLE).unwrap();
+        dict.with_ron("ru", EXAMPLE_RU).unwrap();
+
+        assert_eq!(dict.available_locales(), ["de", "fr", "ru"].into_iter().map(Into::into).collect());
+
+        // Exact match wins over everything else.
+        assert_eq!(dict.negotiate(&["fr"]).as_deref(), Some("fr"));
+
+        // Regional subtag falls back to the available primary language.
+        assert_eq!(dict.negotiate(&["de-AT"]).as_deref(), Some("de"));
+
+        // Unavailable languages are skipped in favor of the next preference.
+        assert_eq!(dict.negotiate(&["jp", "fr"]).as_deref(), Some("fr"));
+
+        // Nothing in the dictionary matches any preference.
+        assert_eq!(dict.negotiate(&["jp"]), None);
+
+        assert_eq!(dict.set_locale_from(&["de-AT"]).as_deref(), Some("de"));
+        assert_eq!(dict.locale.as_deref(), Some("de"));
+    }
+
+    #[test]
+    fn negotiate_is_deterministic_across_runs() {
+        // Two locales share the requested primary language but neither is an exact or
+        // primary-subtag match; the result must be stable regardless of HashSet iteration order.
+        let mut dict = Dictionary::new();
+        dict.with_ron("en-AU", r#"{ "Hello": "G'day" }"#).unwrap();
+        dict.with_ron("en-GB", r#"{ "Hello": "Hello" }"#).unwrap();
+
+        let expected = dict.negotiate(&["en-NZ"]);
+        for _ in 0..20 {
+            assert_eq!(dict.negotiate(&["en-NZ"]), expected);
+        }
+        assert_eq!(expected.as_deref(), Some("en-AU"));
+    }
+
+    #[test]
+    fn pseudo_locale() {
+        let mut dict = Dictionary::from_ron(EXAMPLE).unwrap();
+        dict.locale = Some(i18nx::PSEUDO_LOCALE.into());
+
+        // No resource lookup happens: the source template is transformed instead.
+        let transformed = dict.get("Hello {name}!").unwrap();
+        assert!(transformed.starts_with('[') && transformed.ends_with(']'));
+        assert!(transformed.contains("{name}"));
+        assert_ne!(transformed, "Hello {name}!");
+    }
+
+    #[test]
+    fn pseudo_locale_applies_to_plurals() {
+        let mut dict = Dictionary::from_ron(EXAMPLE_PLURAL).unwrap();
+        dict.locale = Some(i18nx::PSEUDO_LOCALE.into());
+
+        // tn!/get_plural transform the source template too, instead of missing the pseudo
+        // locale in the resource lookup and silently falling back to None.
+        let transformed = dict.get_plural("{n} files", 5).unwrap();
+        assert!(transformed.starts_with('[') && transformed.ends_with(']'));
+        assert!(transformed.contains("{n}"));
+        assert_ne!(transformed, "{n} files");
+    }
+
+    #[test]
+    fn load_file_infers_locale_from_filename() {
+        let mut dict = Dictionary::new();
+        dict.load_file(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/locales/demo.ru.ron")).unwrap();
+
+        dict.locale = Some("ru".into());
+        assert_eq!(dict.get("Hello").unwrap(), "Привет");
+    }
+
+    #[test]
+    fn load_file_owns_translation_content() {
+        // load_file reads from disk at runtime, so loaded content is stored as an owned Cow
+        // rather than leaked to fake a 'static lifetime.
+        let mut dict = Dictionary::new();
+        dict.load_file(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/locales/demo.ru.ron")).unwrap();
+
+        let translation = dict.resource.get("Hello").unwrap().get("ru").unwrap();
+        assert!(matches!(translation, Translation::Plain(std::borrow::Cow::Owned(_))));
+    }
+
+    #[test]
+    fn load_dir_loads_every_matching_file() {
+        let mut dict = Dictionary::new();
+        dict.load_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/locales/*.ron")).unwrap();
+
+        dict.locale = Some("ru".into());
+        assert_eq!(dict.get("Hello").unwrap(), "Привет");
+        dict.locale = Some("cn".into());
+        assert_eq!(dict.get("Hello").unwrap(), "你好");
+    }
+
+    #[test]
+    fn missing_translations() {
+        let mut dict = Dictionary::from_ron(EXAMPLE).unwrap();
+        dict.log_missing = true;
+
+        dict.locale = Some("cn".into());
+        assert_eq!(dict.get("Hello"), None);
+        assert_eq!(dict.get("Hello {name}!"), None);
+        assert!(dict.missing().contains(&("cn".into(), "Hello")));
+        assert!(dict.missing().contains(&("cn".into(), "Hello {name}!")));
+
+        // A hit is not recorded as missing.
+        dict.locale = Some("de".into());
+        assert_eq!(dict.get("Hello").unwrap(), "Hallo");
+        assert!(!dict.missing().contains(&("de".into(), "Hello")));
+
+        let drained = dict.take_missing();
+        assert_eq!(drained.len(), 2);
+        assert!(dict.missing().is_empty());
+    }
+
+    #[test]
+    fn plural_categories_by_locale() {
+        let mut dict = Dictionary::from_ron(EXAMPLE_PLURAL).unwrap();
+
+        dict.locale = Some("en".into());
+        assert_eq!(dict.get_plural("{n} files", 1).unwrap(), "{n} file");
+        assert_eq!(dict.get_plural("{n} files", 5).unwrap(), "{n} files");
+
+        dict.locale = Some("ru".into());
+        assert_eq!(dict.get_plural("{n} files", 1).unwrap(), "{n} файл");
+        assert_eq!(dict.get_plural("{n} files", 2).unwrap(), "{n} файла");
+        assert_eq!(dict.get_plural("{n} files", 5).unwrap(), "{n} файлов");
+        assert_eq!(dict.get_plural("{n} files", 11).unwrap(), "{n} файлов");
+        assert_eq!(dict.get_plural("{n} files", 21).unwrap(), "{n} файл");
+    }
 }